@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+
+use haz::{access_from, Has};
+
+fn main() {
+    let env = Env {
+        host: Host("localhost".into()),
+        port: Port(8080),
+        verbosity: Verbosity::High,
+        abort_on_error: true,
+        timeout: RawTimeout {
+            timeout: Timeout(30),
+            source: "default",
+        },
+    };
+
+    run_with(env);
+}
+
+fn run_with<E>(env: E)
+where
+    E: Has<Host> + Has<Port> + Has<Verbosity> + Has<Timeout>,
+{
+    let host: &Host = env.access();
+    let port = access_from::<Port, _>(&env);
+    let verbosity: &Verbosity = env.access();
+    let timeout: &Timeout = env.access();
+
+    println!(
+        "host: {:?}, port: {:?}, verbosity: {:?}, timeout: {:?}",
+        host, port, verbosity, timeout
+    )
+}
+
+#[derive(Debug, Has)]
+struct Env {
+    host: Host,
+    port: Port,
+    verbosity: Verbosity,
+    // Not meant to be accessed through `Has`, so it is excluded from the derivation.
+    #[haz(skip)]
+    abort_on_error: bool,
+    // `RawTimeout` bundles `Timeout` with where it came from, so it is exposed as `Timeout`
+    // through `Has` rather than as itself.
+    #[haz(as = "Timeout")]
+    timeout: RawTimeout,
+}
+
+#[derive(Debug)]
+struct Host(String);
+
+#[derive(Debug)]
+struct Port(u16);
+
+#[derive(Debug)]
+enum Verbosity {
+    Low,
+    High,
+}
+
+#[derive(Debug)]
+struct RawTimeout {
+    timeout: Timeout,
+    source: &'static str,
+}
+
+impl AsRef<Timeout> for RawTimeout {
+    fn as_ref(&self) -> &Timeout {
+        &self.timeout
+    }
+}
+
+impl AsMut<Timeout> for RawTimeout {
+    fn as_mut(&mut self) -> &mut Timeout {
+        &mut self.timeout
+    }
+}
+
+#[derive(Debug)]
+struct Timeout(u32);
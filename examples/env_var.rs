@@ -0,0 +1,38 @@
+#![allow(dead_code)]
+
+use haz::env::{EnvComponent, FromEnv};
+use haz::{access, Has};
+use std::str::FromStr;
+
+fn main() {
+    std::env::set_var("PORT", "8080");
+
+    run_with(&FromEnv);
+}
+
+fn run_with<E>(env: &E)
+where
+    E: Has<Port>,
+{
+    let port: &Port = env.access();
+    let same_port = access::<Port>().from(env);
+
+    println!("port: {:?}, same_port: {:?}", port, same_port);
+}
+
+#[derive(Debug)]
+struct Port(u16);
+
+impl FromStr for Port {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Port)
+    }
+}
+
+impl EnvComponent for Port {
+    fn var_name() -> &'static str {
+        "PORT"
+    }
+}
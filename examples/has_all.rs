@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use haz::{access_many, HasAll};
+
+fn main() {
+    let env = Env {
+        host: Host("localhost".into()),
+        port: Port(8080),
+        verbosity: Verbosity::High,
+    };
+
+    run_with(&env);
+}
+
+// A single `HasAll` bound stands in for `Has<Host> + Has<Port> + Has<Verbosity>`.
+fn run_with<E>(env: &E)
+where
+    E: for<'a> HasAll<'a, (&'a Host, &'a Port, &'a Verbosity)>,
+{
+    let (host, port, verbosity) = access_many::<(&Host, &Port, &Verbosity), _>(env);
+
+    println!("host: {:?}, port: {:?}, verbosity: {:?}", host, port, verbosity)
+}
+
+#[derive(Debug)]
+struct Env {
+    host: Host,
+    port: Port,
+    verbosity: Verbosity,
+}
+
+#[derive(Debug)]
+struct Host(String);
+
+#[derive(Debug)]
+struct Port(u16);
+
+#[derive(Debug)]
+enum Verbosity {
+    Low,
+    High,
+}
+
+haz::impl_has_for_named_component!(Env, Host, host);
+haz::impl_has_for_named_component!(Env, Port, port);
+haz::impl_has_for_named_component!(Env, Verbosity, verbosity);
@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+use haz::{access, access_mut_from, Has, HasMut};
+
+fn main() {
+    let mut env = Env {
+        host: Host("localhost".into()),
+        retries: RawRetries {
+            retries: Retries(3),
+            source: "cli",
+        },
+    };
+
+    bump_retries(&mut env);
+
+    let host: &Host = env.access();
+    let retries: &Retries = env.access();
+
+    println!("host: {:?}, retries: {:?}", host, retries)
+}
+
+fn bump_retries<E>(env: &mut E)
+where
+    E: HasMut<Retries>,
+{
+    access_mut_from::<Retries, _>(env).0 += 1;
+    access::<Retries>().from_mut(env).0 += 1;
+}
+
+#[derive(Debug, Has)]
+struct Env {
+    host: Host,
+    // `RawRetries` bundles `Retries` with extra bookkeeping, so it is exposed as `Retries`
+    // through `Has`/`HasMut` rather than as itself.
+    #[haz(as = "Retries")]
+    retries: RawRetries,
+}
+
+#[derive(Debug)]
+struct Host(String);
+
+#[derive(Debug)]
+struct RawRetries {
+    retries: Retries,
+    source: &'static str,
+}
+
+impl AsRef<Retries> for RawRetries {
+    fn as_ref(&self) -> &Retries {
+        &self.retries
+    }
+}
+
+impl AsMut<Retries> for RawRetries {
+    fn as_mut(&mut self) -> &mut Retries {
+        &mut self.retries
+    }
+}
+
+#[derive(Debug)]
+struct Retries(u8);
@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+
+use haz::overlay::Overlay;
+use haz::Has;
+use std::rc::Rc;
+
+type Env = Overlay<CliArgs, Defaults>;
+
+// `Host` may be overridden on the command line, so it is always read from `primary`;
+// `fallback`'s `Host` is never consulted.
+haz::overlay::prefer_primary!(Env, Host);
+
+// `Port` is never passed on the command line, so it is always read from `fallback`.
+haz::overlay::prefer_fallback!(Env, Port);
+
+fn main() {
+    let env: Env = Overlay {
+        primary: CliArgs {
+            host: Host("cli-host".into()),
+        },
+        fallback: Defaults {
+            host: Host("default-host".into()),
+            port: Port(8080),
+        },
+    };
+
+    // `Has` is blanket-forwarded through `Rc` (and `&_`, `Box<_>`, `Arc<_>`), so a shared
+    // environment works with `run_with` unchanged.
+    run_with(Rc::new(env));
+}
+
+fn run_with<E>(env: E)
+where
+    E: Has<Host> + Has<Port>,
+{
+    let host: &Host = env.access();
+    let port: &Port = env.access();
+
+    println!("host: {:?}, port: {:?}", host, port)
+}
+
+struct CliArgs {
+    host: Host,
+}
+
+struct Defaults {
+    host: Host,
+    port: Port,
+}
+
+#[derive(Debug)]
+struct Host(String);
+
+#[derive(Debug)]
+struct Port(u16);
+
+haz::impl_has_for_named_component!(CliArgs, Host, host);
+haz::impl_has_for_named_component!(Defaults, Host, host);
+haz::impl_has_for_named_component!(Defaults, Port, port);
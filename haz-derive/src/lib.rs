@@ -0,0 +1,199 @@
+//! The companion derive macro for [`haz::Has`], re-exported as `haz::Has`.
+//!
+//! Deriving `Has` on a struct generates one `impl haz::Has<T>` per field, keyed on the
+//! field's own type.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use haz::Has;
+//!
+//! #[derive(Has)]
+//! struct Env {
+//!   host: Host,
+//!   port: Port,
+//!   #[haz(skip)]
+//!   debug: bool,
+//! }
+//!
+//! struct Host(String);
+//! struct Port(u16);
+//! ```
+//!
+//! Two attributes steer the derivation:
+//!
+//! - `#[haz(skip)]` excludes a field entirely, e.g. `abort_on_error` or `debug` flags
+//!   that are not meant to be accessed through `Has`.
+//! - `#[haz(as = "Component")]` is used when the type exposed through `Has`/`HasMut` differs
+//!   from the field's own type; the field's type must then implement `AsRef<Component>` and
+//!   `AsMut<Component>`.
+//!
+//! Two fields may not expose the same component type, since the resulting `Has<T>` impls
+//! would be ambiguous; the derive rejects this at compile time.
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Ident, LitStr, Type};
+
+/// Derives [`haz::Has<T>`] for every (non-skipped) field of a struct, keyed on the
+/// field's type, or on the type named by `#[haz(as = "...")]` when present.
+#[proc_macro_derive(Has, attributes(haz))]
+pub fn derive_has(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A single field selected to back a `Has<T>` impl.
+struct Component {
+    field: Ident,
+    stored_type: Type,
+    exposed_type: Type,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let container = input.ident;
+
+    let fields = match input.data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => fields.named,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "`#[derive(Has)]` only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                container,
+                "`#[derive(Has)]` only supports structs with named fields",
+            ))
+        }
+    };
+
+    let mut components = Vec::new();
+    for field in fields {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let stored_type = field.ty;
+        let exposed_type = attrs.as_type.unwrap_or_else(|| stored_type.clone());
+
+        components.push(Component {
+            field: field.ident.expect("named field has an identifier"),
+            stored_type,
+            exposed_type,
+        });
+    }
+
+    reject_duplicate_components(&components)?;
+
+    let impls = components.iter().map(|component| {
+        let field = &component.field;
+        let exposed_type = &component.exposed_type;
+
+        if types_match(&component.stored_type, exposed_type) {
+            quote! {
+                impl haz::Has<#exposed_type> for #container {
+                    fn access(&self) -> &#exposed_type {
+                        &self.#field
+                    }
+                }
+
+                impl haz::HasMut<#exposed_type> for #container {
+                    fn access_mut(&mut self) -> &mut #exposed_type {
+                        &mut self.#field
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl haz::Has<#exposed_type> for #container {
+                    fn access(&self) -> &#exposed_type {
+                        ::std::convert::AsRef::as_ref(&self.#field)
+                    }
+                }
+
+                impl haz::HasMut<#exposed_type> for #container {
+                    fn access_mut(&mut self) -> &mut #exposed_type {
+                        ::std::convert::AsMut::as_mut(&mut self.#field)
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(quote! { #(#impls)* })
+}
+
+/// Parsed `#[haz(...)]` attributes for a single field.
+#[derive(Default)]
+struct FieldAttrs {
+    skip: bool,
+    as_type: Option<Type>,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("haz") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    parsed.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("as") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.as_type = Some(lit.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `haz` attribute, expected `skip` or `as`"))
+                }
+            })?;
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn types_match(a: &Type, b: &Type) -> bool {
+    quote!(#a).to_string() == quote!(#b).to_string()
+}
+
+fn reject_duplicate_components(components: &[Component]) -> syn::Result<()> {
+    for (i, a) in components.iter().enumerate() {
+        for b in &components[i + 1..] {
+            if types_match(&a.exposed_type, &b.exposed_type) {
+                let ty = &a.exposed_type;
+                let exposed_type = quote!(#ty).to_string();
+                let mut error = syn::Error::new_spanned(
+                    &a.field,
+                    format!(
+                        "field `{}` exposes `{}` via `Has`, but so does field `{}`; \
+                         only one field may expose a given component type",
+                        a.field, exposed_type, b.field
+                    ),
+                );
+                error.combine(syn::Error::new_spanned(
+                    &b.field,
+                    format!("`{}` also exposes `{}` here", b.field, exposed_type),
+                ));
+                return Err(error);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+// Two fields exposing the same component type through `Has` would produce ambiguous impls,
+// so the derive rejects this at compile time.
+
+use haz::Has;
+
+#[derive(Has)]
+struct Env {
+    host: Host,
+    other_host: Host,
+}
+
+struct Host(String);
+
+fn main() {}
@@ -0,0 +1,132 @@
+//! An opt-in provider that implements [`Has`] by reading and parsing components straight from
+//! the process environment.
+//!
+//! # Example
+//!
+//! ```
+//! use haz::env::{EnvComponent, FromEnv};
+//! use haz::{access, Has};
+//! use std::str::FromStr;
+//!
+//! #[derive(Debug)]
+//! struct Port(u16);
+//!
+//! impl FromStr for Port {
+//!   type Err = std::num::ParseIntError;
+//!
+//!   fn from_str(s: &str) -> Result<Self, Self::Err> {
+//!     s.parse().map(Port)
+//!   }
+//! }
+//!
+//! impl EnvComponent for Port {
+//!   fn var_name() -> &'static str {
+//!     "PORT"
+//!   }
+//! }
+//!
+//! std::env::set_var("PORT", "8080");
+//!
+//! let port = access::<Port>().from(&FromEnv);
+//! ```
+//!
+//! A missing or unparseable variable panics through [`Has::access`], mirroring the trait's
+//! infallible contract; use [`FromEnv::try_access`] instead to handle that case explicitly.
+
+use crate::Has;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+
+/// A component that can be parsed from a single environment variable.
+///
+/// Implement this for any `T: FromStr` to make it accessible through [`FromEnv`].
+pub trait EnvComponent: FromStr + Send + Sync + Sized + 'static {
+    /// The name of the environment variable backing this component.
+    fn var_name() -> &'static str;
+}
+
+/// An error sourcing a component from the environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvError {
+    /// The named environment variable was not set.
+    Missing(&'static str),
+    /// The named environment variable could not be parsed into the component.
+    Invalid(&'static str, String),
+}
+
+impl std::fmt::Display for EnvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnvError::Missing(name) => write!(f, "environment variable `{name}` is not set"),
+            EnvError::Invalid(name, reason) => {
+                write!(f, "environment variable `{name}` could not be parsed: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EnvError {}
+
+/// A [`Has`] provider that reads, parses, and caches components from the process environment.
+///
+/// Once a component has been read and parsed successfully, the parsed value is cached for the
+/// rest of the process so repeated accesses are cheap. A missing or unparseable variable is not
+/// cached: it is read and parsed again on the next access.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FromEnv;
+
+impl FromEnv {
+    /// Reads, parses, and caches the component named by [`EnvComponent::var_name`].
+    ///
+    /// Returns [`EnvError::Missing`] if the variable is unset, or [`EnvError::Invalid`] if it
+    /// could not be parsed into `Component`.
+    pub fn try_access<Component>(&self) -> Result<&Component, EnvError>
+    where
+        Component: EnvComponent,
+        Component::Err: std::fmt::Display,
+    {
+        cached::<Component>()
+    }
+}
+
+impl<Component> Has<Component> for FromEnv
+where
+    Component: EnvComponent,
+    Component::Err: std::fmt::Display,
+{
+    fn access(&self) -> &Component {
+        self.try_access()
+            .unwrap_or_else(|err| panic!("failed to access component from the environment: {err}"))
+    }
+}
+
+type Cache = Mutex<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>;
+
+static CACHE: OnceLock<Cache> = OnceLock::new();
+
+fn cached<Component>() -> Result<&'static Component, EnvError>
+where
+    Component: EnvComponent,
+    Component::Err: std::fmt::Display,
+{
+    let mut cache = CACHE.get_or_init(Cache::default).lock().unwrap();
+
+    if let Some(cached) = cache.get(&TypeId::of::<Component>()) {
+        return Ok(cached
+            .downcast_ref::<Component>()
+            .expect("cache entry is keyed by TypeId::of::<Component>()"));
+    }
+
+    let raw = std::env::var(Component::var_name())
+        .map_err(|_| EnvError::Missing(Component::var_name()))?;
+    let parsed = raw
+        .parse::<Component>()
+        .map_err(|err| EnvError::Invalid(Component::var_name(), err.to_string()))?;
+
+    let leaked: &'static Component = Box::leak(Box::new(parsed));
+    cache.insert(TypeId::of::<Component>(), leaked);
+
+    Ok(leaked)
+}
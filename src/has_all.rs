@@ -0,0 +1,50 @@
+//! Bulk, type-directed access to several components at once.
+//!
+//! `Refs` is spelled as a tuple of *references*, e.g. `(&Host, &Port)`, rather than a tuple of
+//! bare component types: associating a tuple of owned types with its borrowed counterpart would
+//! need a generic associated type, and `Self::Refs<'_>` does not normalize to a concrete tuple
+//! outside of its own impl, which rules out destructuring it in generic code. Naming the
+//! reference tuple directly sidesteps that, at the cost of a `for<'a>` in the where-bound.
+//!
+//! This is a deliberate trade-off, not an oversight: a bare-type-tuple `HasAll<(Host, Port)>`
+//! reads a little closer to [`Has`], but the GAT it requires doesn't normalize generically, so
+//! it was dropped in favor of the reference-tuple shape above, which does.
+
+use crate::Has;
+
+/// A representation of a type which can give access to every component named by `Refs`, a tuple
+/// of references, e.g. `(&Host, &Port)`.
+///
+/// It is blanket-implemented for any container that implements [`Has`] for each referenced
+/// component type, up to arity 12, so a where-bound like `C: Has<Host> + Has<Port>` can instead
+/// be written `C: for<'a> HasAll<'a, (&'a Host, &'a Port)>`.
+pub trait HasAll<'a, Refs> {
+    /// Borrows read-only access to every component named by `Refs`, in the same order.
+    fn access_all(&'a self) -> Refs;
+}
+
+macro_rules! impl_has_all_for_tuple {
+    ($($component:ident),+) => {
+        impl<'a, Container, $($component),+> HasAll<'a, ($(&'a $component,)+)> for Container
+        where
+            $(Container: Has<$component>, $component: 'a,)+
+        {
+            fn access_all(&'a self) -> ($(&'a $component,)+) {
+                ($(<Self as Has<$component>>::access(self),)+)
+            }
+        }
+    };
+}
+
+impl_has_all_for_tuple!(T1);
+impl_has_all_for_tuple!(T1, T2);
+impl_has_all_for_tuple!(T1, T2, T3);
+impl_has_all_for_tuple!(T1, T2, T3, T4);
+impl_has_all_for_tuple!(T1, T2, T3, T4, T5);
+impl_has_all_for_tuple!(T1, T2, T3, T4, T5, T6);
+impl_has_all_for_tuple!(T1, T2, T3, T4, T5, T6, T7);
+impl_has_all_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8);
+impl_has_all_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_has_all_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_has_all_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+impl_has_all_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
@@ -50,12 +50,66 @@
 
 #![deny(missing_docs)]
 
+pub mod env;
+mod has_all;
+pub mod overlay;
+
+/// Derives [`Has<T>`] for every (non-skipped) field of a struct.
+///
+/// See the [`haz_derive`] crate documentation for the supported `#[haz(..)]` attributes.
+pub use haz_derive::Has;
+
+/// Bulk access to every component named by a tuple of references, e.g. `(&Host, &Port)`.
+pub use has_all::HasAll;
+
 /// A representation of a type which can give access to some `Component`.
 pub trait Has<Component> {
     /// Borrows read-only access to a component of the parent container.
     fn access(&self) -> &Component;
 }
 
+/// A representation of a type which can give mutable access to some `Component`.
+pub trait HasMut<Component>: Has<Component> {
+    /// Borrows mutable access to a component of the parent container.
+    fn access_mut(&mut self) -> &mut Component;
+}
+
+impl<Container, Component> Has<Component> for &Container
+where
+    Container: Has<Component> + ?Sized,
+{
+    fn access(&self) -> &Component {
+        (**self).access()
+    }
+}
+
+impl<Container, Component> Has<Component> for Box<Container>
+where
+    Container: Has<Component> + ?Sized,
+{
+    fn access(&self) -> &Component {
+        (**self).access()
+    }
+}
+
+impl<Container, Component> Has<Component> for std::rc::Rc<Container>
+where
+    Container: Has<Component> + ?Sized,
+{
+    fn access(&self) -> &Component {
+        (**self).access()
+    }
+}
+
+impl<Container, Component> Has<Component> for std::sync::Arc<Container>
+where
+    Container: Has<Component> + ?Sized,
+{
+    fn access(&self) -> &Component {
+        (**self).access()
+    }
+}
+
 /// Accesses a component from its container via a turbofish-friendly syntax.
 pub fn access_from<Component, Container>(container: &Container) -> &Component
 where
@@ -64,6 +118,22 @@ where
     container.access()
 }
 
+/// Mutably accesses a component from its container via a turbofish-friendly syntax.
+pub fn access_mut_from<Component, Container>(container: &mut Container) -> &mut Component
+where
+    Container: HasMut<Component>,
+{
+    container.access_mut()
+}
+
+/// Accesses every component named by `Refs` from its container via a turbofish-friendly syntax.
+pub fn access_many<'a, Refs, Container>(container: &'a Container) -> Refs
+where
+    Container: HasAll<'a, Refs>,
+{
+    container.access_all()
+}
+
 /// Helper to give access to a component via a turbofish-friendly, infix syntax.
 #[derive(Debug)]
 pub struct Accessor<Component>(std::marker::PhantomData<Component>);
@@ -79,6 +149,30 @@ impl<Component> Accessor<Component> {
     {
         container.access()
     }
+
+    /// Mutably accesses a component from its container.
+    ///
+    /// This function simply delegates to the trait's method, but it might be
+    /// interesting for those who prefer turbofish to annotate types combined with an infix notation.
+    pub fn from_mut<'c, Container>(&self, container: &'c mut Container) -> &'c mut Component
+    where
+        Container: HasMut<Component>,
+    {
+        container.access_mut()
+    }
+}
+
+impl<Refs> Accessor<Refs> {
+    /// Accesses every component named by `Refs` from its container, borrowed together.
+    ///
+    /// This function simply delegates to the trait's method, but it might be
+    /// interesting for those who prefer turbofish to annotate types combined with an infix notation.
+    pub fn from_all<'c, Container>(&self, container: &'c Container) -> Refs
+    where
+        Container: HasAll<'c, Refs>,
+    {
+        container.access_all()
+    }
 }
 
 /// Constructs a proxy from which one may access a component from its container via a turbofish-friendly, infix syntax.
@@ -86,7 +180,7 @@ pub fn access<Component>() -> Accessor<Component> {
     Accessor(std::marker::PhantomData)
 }
 
-/// Implements [`Has`] for a container which can give access to a component.
+/// Implements [`Has`] and [`HasMut`] for a container which can give access to a component.
 ///
 /// # Example
 ///
@@ -107,5 +201,11 @@ macro_rules! impl_has_for_named_component {
                 &self.$component_name
             }
         }
+
+        impl haz::HasMut<$component_type> for $container_type {
+            fn access_mut(&mut self) -> &mut $component_type {
+                &mut self.$component_name
+            }
+        }
     };
 }
@@ -0,0 +1,87 @@
+//! Compose two containers into one environment with explicit precedence between them.
+//!
+//! # Example
+//!
+//! ```
+//! use haz::overlay::Overlay;
+//! use haz::Has;
+//!
+//! struct CliArgs {
+//!   host: Host,
+//! }
+//!
+//! struct Defaults {
+//!   host: Host,
+//!   port: Port,
+//! }
+//!
+//! #[derive(Debug)]
+//! struct Host(String);
+//!
+//! #[derive(Debug)]
+//! struct Port(u16);
+//!
+//! haz::impl_has_for_named_component!(CliArgs, Host, host);
+//! haz::impl_has_for_named_component!(Defaults, Host, host);
+//! haz::impl_has_for_named_component!(Defaults, Port, port);
+//!
+//! type Env = Overlay<CliArgs, Defaults>;
+//!
+//! haz::overlay::prefer_primary!(Env, Host);
+//! haz::overlay::prefer_fallback!(Env, Port);
+//!
+//! let env = Overlay {
+//!   primary: CliArgs { host: Host("cli-host".into()) },
+//!   fallback: Defaults { host: Host("default-host".into()), port: Port(8080) },
+//! };
+//!
+//! let host: &Host = env.access(); // read from `primary`
+//! let port: &Port = env.access(); // read from `fallback`
+//! ```
+
+/// Combines a `Primary` and a `Fallback` container into a single environment.
+///
+/// `Overlay` does not decide on its own which side a component is read from: [`Has`](crate::Has)
+/// is not blanket-implemented for it, since a single "try primary, then fallback" impl would be
+/// ambiguous whenever both sides expose the same component type. Instead, opt each component
+/// type in explicitly with [`prefer_primary!`] or [`prefer_fallback!`], one invocation per
+/// component.
+#[derive(Debug, Clone, Copy)]
+pub struct Overlay<Primary, Fallback> {
+    /// The container consulted for components opted in via [`prefer_primary!`].
+    pub primary: Primary,
+    /// The container consulted for components opted in via [`prefer_fallback!`].
+    pub fallback: Fallback,
+}
+
+/// Implements [`Has<Component>`](crate::Has) for an [`Overlay`] by reading it from `primary`.
+///
+/// See the [module documentation](self) for a full, runnable example.
+#[macro_export]
+macro_rules! prefer_primary {
+    ($overlay_type:ty, $component_type:ty) => {
+        impl haz::Has<$component_type> for $overlay_type {
+            fn access(&self) -> &$component_type {
+                haz::Has::access(&self.primary)
+            }
+        }
+    };
+}
+
+/// Implements [`Has<Component>`](crate::Has) for an [`Overlay`] by reading it from `fallback`.
+///
+/// See [`prefer_primary!`] for the counterpart that reads from `primary`, and the
+/// [module documentation](self) for a full, runnable example.
+#[macro_export]
+macro_rules! prefer_fallback {
+    ($overlay_type:ty, $component_type:ty) => {
+        impl haz::Has<$component_type> for $overlay_type {
+            fn access(&self) -> &$component_type {
+                haz::Has::access(&self.fallback)
+            }
+        }
+    };
+}
+
+pub use crate::prefer_fallback;
+pub use crate::prefer_primary;